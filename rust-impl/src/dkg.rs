@@ -0,0 +1,175 @@
+/*!
+Dealerless distributed key generation (Pedersen DKG)
+
+Lets `n` participants jointly produce a [`threshold`](crate::threshold)
+group key with no trusted dealer, following the SimplPedPoP structure:
+every participant deals shares of its own secret polynomial to every
+other participant, backed by a Feldman commitment that lets each
+recipient verify its share without learning the polynomial. Once a
+quorum of dealers `Q` is agreed, each participant sums the shares it
+received from `Q` into its final secret key share, exactly as `combine`
+expects.
+
+The four rounds are exposed as explicit message types and free functions
+(`round1_commit`, `round2_deal`, `verify_share`, `finalize`) so a caller
+can drive them over its own network transport.
+*/
+
+use crate::signature::BLSSigCore;
+use crate::threshold::{PublicKeyShare, SecretKeyShare, SecretKeySet};
+use ff::{Field, PrimeField};
+use pairing::CurveProjective;
+use rand::RngCore;
+
+/// Alias for the scalar field of the curve a `BLSSigCore` impl signs over.
+type Scalar<C> = <C as CurveProjective>::Scalar;
+
+fn index_to_scalar<C: BLSSigCore>(index: u64) -> Scalar<C> {
+    Scalar::<C>::from_str(&index.to_string()).expect("index fits in the scalar field")
+}
+
+/// Round-1 broadcast message: a dealer's Feldman commitment
+/// `C = [g^{a_0}, ..., g^{a_t}]` to its secret sharing polynomial.
+#[derive(Clone)]
+pub struct Commitment<C: BLSSigCore> {
+    coeffs: Vec<C::PKType>,
+}
+
+impl<C: BLSSigCore> Commitment<C> {
+    /// Evaluate the commitment at `index`, computing
+    /// `\prod_{m=0}^{t} C_m^{index^m}` without knowing the polynomial.
+    fn evaluate(&self, index: u64) -> C::PKType {
+        let x = index_to_scalar::<C>(index);
+        let mut acc = C::PKType::zero();
+        let mut xpow = Scalar::<C>::one();
+        for c in &self.coeffs {
+            let mut term = *c;
+            term.mul_assign(xpow);
+            acc.add_assign(&term);
+            xpow.mul_assign(&x);
+        }
+        acc
+    }
+}
+
+/// Round 1: sample this dealer's secret polynomial and the public
+/// commitment to broadcast to every other participant. The polynomial
+/// itself must be kept private and used only to deal shares in round 2.
+pub fn round1_commit<C: BLSSigCore, R: RngCore>(
+    threshold: usize,
+    rng: &mut R,
+) -> (SecretKeySet<C>, Commitment<C>) {
+    let poly = SecretKeySet::<C>::random(threshold, rng);
+    let coeffs = poly.commitment();
+    (poly, Commitment { coeffs })
+}
+
+/// Round 2: deal participant `recipient`'s share `f(recipient)` of this
+/// dealer's polynomial, to be sent over a private, authenticated channel.
+pub fn round2_deal<C: BLSSigCore>(poly: &SecretKeySet<C>, recipient: u64) -> SecretKeyShare<C> {
+    poly.secret_key_share(recipient)
+}
+
+/// Round 3: verify a privately-received share against the dealer's
+/// broadcast commitment. Returns the dealer's index as a complaint on
+/// mismatch, so the recipient can publicly accuse the dealer.
+pub fn verify_share<C: BLSSigCore>(
+    dealer: u64,
+    commitment: &Commitment<C>,
+    share: &SecretKeyShare<C>,
+) -> Result<(), u64> {
+    let mut expected = C::PKType::one();
+    expected.mul_assign(share.x_prime);
+    if expected == commitment.evaluate(share.index()) {
+        Ok(())
+    } else {
+        Err(dealer)
+    }
+}
+
+/// Round 4: once a quorum `Q` of dealers has been agreed (no outstanding
+/// complaints), combine the shares and commitments contributed by `Q`
+/// into this participant's final secret key share, the group public
+/// key, and this participant's final public key share.
+///
+/// `shares` must contain exactly one share per dealer in `Q`, all for
+/// this participant's own `index`; `commitments` must be the matching
+/// commitments from the same dealers.
+pub fn finalize<C: BLSSigCore>(
+    index: u64,
+    shares: &[SecretKeyShare<C>],
+    commitments: &[Commitment<C>],
+) -> (SecretKeyShare<C>, C::PKType, PublicKeyShare<C>) {
+    let final_share = SecretKeyShare::sum(index, shares);
+
+    let mut group_pk = C::PKType::zero();
+    let mut own_pk = C::PKType::zero();
+    for c in commitments {
+        group_pk.add_assign(&c.coeffs[0]);
+        own_pk.add_assign(&c.evaluate(index));
+    }
+
+    (final_share, group_pk, PublicKeyShare { index, pk: own_pk })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::threshold::combine;
+    use pairing::bls12_381::G1;
+    use rand::thread_rng;
+
+    const CIPHERSUITE: u8 = 1;
+    const MSG: &[u8] = b"dkg test message";
+
+    #[test]
+    fn tampered_share_fails_verification() {
+        let mut rng = thread_rng();
+        let (poly, commitment) = round1_commit::<G1, _>(1, &mut rng);
+        let mut share = round2_deal(&poly, 1);
+
+        assert!(verify_share(0, &commitment, &share).is_ok());
+
+        share.x_prime.add_assign(&Scalar::<G1>::one());
+        assert_eq!(verify_share(0, &commitment, &share), Err(0));
+    }
+
+    #[test]
+    fn finalize_reconstructs_a_key_that_combine_verifies() {
+        let mut rng = thread_rng();
+        let threshold = 1;
+
+        // Two dealers run round 1 and round 2 for three participants.
+        let (poly_a, commitment_a) = round1_commit::<G1, _>(threshold, &mut rng);
+        let (poly_b, commitment_b) = round1_commit::<G1, _>(threshold, &mut rng);
+        let commitments = vec![commitment_a.clone(), commitment_b.clone()];
+
+        let finalized: Vec<_> = (1..=3u64)
+            .map(|i| {
+                let share_a = round2_deal(&poly_a, i);
+                let share_b = round2_deal(&poly_b, i);
+                assert!(verify_share(0, &commitment_a, &share_a).is_ok());
+                assert!(verify_share(1, &commitment_b, &share_b).is_ok());
+
+                finalize(i, &[share_a, share_b], &commitments)
+            })
+            .collect();
+
+        let group_pk = finalized[0].1;
+        assert!(finalized.iter().all(|(_, pk, _)| *pk == group_pk));
+
+        // Every participant's own public key share matches what its
+        // final secret share actually produces.
+        for (final_share, _, own_pk) in &finalized {
+            assert!(final_share.public_key_share().public_key() == own_pk.public_key());
+        }
+
+        let partials: Vec<(u64, G1)> = finalized
+            .iter()
+            .map(|(share, _, _)| (share.index(), share.sign(MSG, CIPHERSUITE)))
+            .collect();
+
+        let sig = combine::<G1>(&partials, threshold).expect("t+1 shares should combine");
+        assert!(G1::core_verify(group_pk, sig, MSG, CIPHERSUITE));
+    }
+}
@@ -0,0 +1,8 @@
+/*!
+BLS signatures on BLS12-381, plus threshold and wire-format extensions.
+*/
+
+pub mod dkg;
+pub mod signature;
+pub mod threshold;
+pub mod wire;
@@ -9,14 +9,29 @@ use pairing::hash_to_curve::HashToCurve;
 use pairing::hash_to_field::BaseFromRO;
 use pairing::serdes::SerDes;
 use pairing::{CurveAffine, CurveProjective, Engine};
+use rand::RngCore;
 use sha2::digest::generic_array::typenum::U48;
 use sha2::digest::generic_array::GenericArray;
-use sha2::Sha256;
+use sha2::{Digest, Sha256};
 use std::collections::HashSet;
 use std::io::Cursor;
 use std::vec::Vec;
 
+/// Draw a uniformly random nonzero scalar, for use as a batch-verification
+/// coefficient: a zero coefficient would drop its triple from the check.
+fn random_nonzero_scalar<R: RngCore>(rng: &mut R) -> Fr {
+    loop {
+        let r = Fr::random(rng);
+        if r != Fr::zero() {
+            return r;
+        }
+    }
+}
+
 /// Hash a secret key sk to the secret exponent x'; then (PK, SK) = (g^{x'}, x').
+///
+/// Kept for backward compatibility; prefer `keygen_v5`, which follows the
+/// IRTF BLS KeyGen draft and is used by `BLSSigCore::keygen`.
 pub fn xprime_from_sk<B: AsRef<[u8]>>(msg: B) -> Fr {
     let mut result = GenericArray::<u8, U48>::default();
     // `result` has enough length to hold the output from HKDF expansion
@@ -26,6 +41,55 @@ pub fn xprime_from_sk<B: AsRef<[u8]>>(msg: B) -> Fr {
     Fr::from_okm(&result)
 }
 
+/// Error returned by `keygen_v5` when `ikm` is shorter than the 32 bytes
+/// the IRTF draft requires.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IkmTooShort(usize);
+
+impl std::fmt::Display for IkmTooShort {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "IKM must be at least 32 bytes, got {}", self.0)
+    }
+}
+
+impl std::error::Error for IkmTooShort {}
+
+/// IRTF BLS-signatures draft "KeyGen": derive the secret exponent x' from
+/// key material `ikm` and optional `key_info`, per the salt-loop HKDF
+/// procedure. Unlike `xprime_from_sk`, a successful result can never be
+/// zero and interoperates with other BLS12-381 implementations (blst,
+/// zkcrypto).
+///
+/// Returns `Err(IkmTooShort)` rather than panicking if `ikm` is shorter
+/// than the 32 bytes the draft requires. `BLSSigCore::keygen` falls back
+/// to the legacy `xprime_from_sk` derivation in that case, so seeds
+/// under 32 bytes stay panic-free but lose the standards-compliant,
+/// cross-implementation-interoperable derivation.
+pub fn keygen_v5<B: AsRef<[u8]>>(ikm: B, key_info: &[u8]) -> Result<Fr, IkmTooShort> {
+    let ikm = ikm.as_ref();
+    if ikm.len() < 32 {
+        return Err(IkmTooShort(ikm.len()));
+    }
+
+    let mut salt = Sha256::digest(b"BLS-SIG-KEYGEN-SALT-").to_vec();
+    loop {
+        let mut ikm_padded = ikm.to_vec();
+        ikm_padded.push(0); // I2OSP(0, 1)
+        let (_, hk) = Hkdf::<Sha256>::extract(Some(&salt), &ikm_padded);
+
+        let mut info = key_info.to_vec();
+        info.extend_from_slice(&48u16.to_be_bytes()); // I2OSP(48, 2)
+        let mut okm = GenericArray::<u8, U48>::default();
+        assert!(hk.expand(&info, &mut okm).is_ok());
+
+        let sk = Fr::from_okm(&okm);
+        if sk != Fr::zero() {
+            return Ok(sk);
+        }
+        salt = Sha256::digest(&salt).to_vec();
+    }
+}
+
 // multi-point-addition helper: used in aggregate and in PoP verify
 fn _agg_help<T: CurveProjective>(ins: &[T]) -> T {
     let mut ret = T::zero();
@@ -47,6 +111,11 @@ pub trait BLSSigCore: CurveProjective {
     /// * input: the secret key as bytes
     /// * output: the actual secret key x_prime, a.k.a, the secret scala
     /// * output: the public key g^x_prime
+    ///
+    /// Derives `x_prime` via `keygen_v5` when `sk` is at least 32 bytes
+    /// (the IRTF draft's minimum IKM length), for interop with other
+    /// BLS12-381 implementations; shorter seeds fall back to the legacy
+    /// `xprime_from_sk` derivation instead of panicking.
     fn keygen<B: AsRef<[u8]>>(sk: B) -> (ScalarT<Self>, Self::PKType);
 
     /// Sign a message
@@ -76,6 +145,29 @@ pub trait BLSSigCore: CurveProjective {
         sig: Self,
         ciphersuite: u8,
     ) -> bool;
+
+    /// Verify many independent (pk, msg, sig) triples at once.
+    ///
+    /// Unlike `core_aggregate_verify`, the triples need not share a
+    /// message or be otherwise related. The `G1`/`G2` implementations
+    /// weight each triple by a fresh random nonzero scalar drawn from
+    /// `rng` and fold the result into a single pairing check, so a set
+    /// of invalid signatures cannot cancel to a valid product the way a
+    /// naive unweighted sum could for distinct keys and messages.
+    ///
+    /// The default implementation here just checks each triple with
+    /// `core_verify` (ignoring `rng`), so implementors of this trait
+    /// outside this crate get a correct, if unbatched, behavior for
+    /// free rather than a forced breaking change.
+    fn batch_verify<B: AsRef<[u8]>, R: RngCore>(
+        items: &[(Self::PKType, B, Self)],
+        ciphersuite: u8,
+        _rng: &mut R,
+    ) -> bool {
+        items
+            .iter()
+            .all(|(pk, msg, sig)| Self::core_verify(*pk, *sig, msg, ciphersuite))
+    }
 }
 
 /// 'Basic' BLS signature
@@ -215,7 +307,8 @@ impl BLSSigCore for G1 {
     type PKType = G2;
 
     fn keygen<B: AsRef<[u8]>>(sk: B) -> (Fr, G2) {
-        let x_prime = xprime_from_sk(sk);
+        let sk = sk.as_ref();
+        let x_prime = keygen_v5(sk, &[]).unwrap_or_else(|_| xprime_from_sk(sk));
         let mut pk = G2::one();
         pk.mul_assign(x_prime);
         (x_prime, pk)
@@ -282,6 +375,48 @@ impl BLSSigCore for G1 {
             Some(pairingproduct) => pairingproduct == Fq12::one(),
         }
     }
+
+    fn batch_verify<B: AsRef<[u8]>, R: RngCore>(
+        items: &[(G2, B, G1)],
+        ciphersuite: u8,
+        rng: &mut R,
+    ) -> bool {
+        if items.is_empty() {
+            return true;
+        }
+
+        let mut sig_acc = G1::zero();
+        let mut pvec =
+            Vec::<<<G1 as CurveProjective>::Affine as CurveAffine>::Prepared>::with_capacity(
+                items.len() + 1,
+            );
+        let mut qvec =
+            Vec::<<<G2 as CurveProjective>::Affine as CurveAffine>::Prepared>::with_capacity(
+                items.len() + 1,
+            );
+        for (pk, msg, sig) in items {
+            let r = random_nonzero_scalar(rng);
+
+            let mut rh = G1::hash_to_curve(msg, ciphersuite);
+            rh.mul_assign(r);
+            pvec.push(rh.into_affine().prepare());
+            qvec.push(pk.into_affine().prepare());
+
+            let mut rsig = *sig;
+            rsig.mul_assign(r);
+            sig_acc.add_assign(&rsig);
+        }
+        pvec.push(sig_acc.into_affine().prepare());
+        let mut g2gen = G2::one();
+        g2gen.negate();
+        qvec.push(g2gen.into_affine().prepare());
+
+        let pqz: Vec<_> = pvec.as_slice().iter().zip(qvec.as_slice()).collect();
+        match Bls12::final_exponentiation(&Bls12::miller_loop(&pqz[..])) {
+            None => false,
+            Some(pairingproduct) => pairingproduct == Fq12::one(),
+        }
+    }
 }
 
 impl BLSSignaturePop for G1 {
@@ -321,7 +456,8 @@ impl BLSSigCore for G2 {
     type PKType = G1;
 
     fn keygen<B: AsRef<[u8]>>(sk: B) -> (Fr, G1) {
-        let x_prime = xprime_from_sk(sk);
+        let sk = sk.as_ref();
+        let x_prime = keygen_v5(sk, &[]).unwrap_or_else(|_| xprime_from_sk(sk));
         let mut pk = G1::one();
         pk.mul_assign(x_prime);
         (x_prime, pk)
@@ -388,6 +524,48 @@ impl BLSSigCore for G2 {
             Some(pairingproduct) => pairingproduct == Fq12::one(),
         }
     }
+
+    fn batch_verify<B: AsRef<[u8]>, R: RngCore>(
+        items: &[(G1, B, G2)],
+        ciphersuite: u8,
+        rng: &mut R,
+    ) -> bool {
+        if items.is_empty() {
+            return true;
+        }
+
+        let mut sig_acc = G2::zero();
+        let mut pvec =
+            Vec::<<<G1 as CurveProjective>::Affine as CurveAffine>::Prepared>::with_capacity(
+                items.len() + 1,
+            );
+        let mut qvec =
+            Vec::<<<G2 as CurveProjective>::Affine as CurveAffine>::Prepared>::with_capacity(
+                items.len() + 1,
+            );
+        for (pk, msg, sig) in items {
+            let r = random_nonzero_scalar(rng);
+
+            pvec.push(pk.into_affine().prepare());
+            let mut rh = G2::hash_to_curve(msg, ciphersuite);
+            rh.mul_assign(r);
+            qvec.push(rh.into_affine().prepare());
+
+            let mut rsig = *sig;
+            rsig.mul_assign(r);
+            sig_acc.add_assign(&rsig);
+        }
+        let mut g1gen = G1::one();
+        g1gen.negate();
+        pvec.push(g1gen.into_affine().prepare());
+        qvec.push(sig_acc.into_affine().prepare());
+
+        let pqz: Vec<_> = pvec.as_slice().iter().zip(qvec.as_slice()).collect();
+        match Bls12::final_exponentiation(&Bls12::miller_loop(&pqz[..])) {
+            None => false,
+            Some(pairingproduct) => pairingproduct == Fq12::one(),
+        }
+    }
 }
 
 impl BLSSignaturePop for G2 {
@@ -422,3 +600,72 @@ impl BLSSignaturePop for G2 {
         G2::core_verify(pk, sig, &pk_bytes[..], ciphersuite)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::thread_rng;
+
+    #[test]
+    fn batch_verify_accepts_valid_batch_and_rejects_tampered_one() {
+        let mut rng = thread_rng();
+        let ciphersuite = 1u8;
+        let msgs: [&[u8]; 3] = [b"alpha", b"beta", b"gamma"];
+
+        let mut items: Vec<(G2, &[u8], G1)> = msgs
+            .iter()
+            .map(|msg| {
+                let (x_prime, pk) = G1::keygen(*msg);
+                let sig = G1::core_sign(x_prime, *msg, ciphersuite);
+                (pk, *msg, sig)
+            })
+            .collect();
+
+        assert!(G1::batch_verify(&items[..], ciphersuite, &mut rng));
+
+        // tamper with one signature in the batch
+        items[1].2.add_assign(&G1::one());
+        assert!(!G1::batch_verify(&items[..], ciphersuite, &mut rng));
+    }
+
+    #[test]
+    fn batch_verify_empty_batch_is_vacuously_true() {
+        let mut rng = thread_rng();
+        let items: Vec<(G2, &[u8], G1)> = Vec::new();
+        assert!(G1::batch_verify(&items[..], 1u8, &mut rng));
+    }
+
+    #[test]
+    fn keygen_v5_is_deterministic_and_nonzero() {
+        let ikm = b"012345678901234567890123456789012345";
+        let sk1 = keygen_v5(&ikm[..], b"").expect("ikm is long enough");
+        let sk2 = keygen_v5(&ikm[..], b"").expect("ikm is long enough");
+        assert!(sk1 == sk2);
+        assert!(sk1 != Fr::zero());
+    }
+
+    #[test]
+    fn keygen_v5_distinguishes_key_info() {
+        let ikm = b"012345678901234567890123456789012345";
+        let sk_a = keygen_v5(&ikm[..], b"key-a").expect("ikm is long enough");
+        let sk_b = keygen_v5(&ikm[..], b"key-b").expect("ikm is long enough");
+        assert!(sk_a != sk_b);
+    }
+
+    #[test]
+    fn keygen_v5_rejects_short_ikm() {
+        assert!(keygen_v5(b"too short", b"").is_err());
+    }
+
+    #[test]
+    fn keygen_falls_back_to_legacy_derivation_for_short_seeds() {
+        // `xprime_from_sk` never panics on short input, so routing `keygen`
+        // through `keygen_v5` must not either.
+        let (x_prime, pk) = G1::keygen(b"short seed");
+        assert!(x_prime == xprime_from_sk(b"short seed"));
+        let mut expected_pk = G2::one();
+        expected_pk.mul_assign(x_prime);
+        assert!(pk == expected_pk);
+    }
+
+}
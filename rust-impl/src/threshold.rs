@@ -0,0 +1,289 @@
+/*!
+Threshold BLS signatures
+
+Shares a BLS secret key among `n` parties via Shamir secret sharing over
+`Fr`, so that any `t+1` of them can jointly produce a signature that
+verifies under a single master public key with the unchanged
+`BLSSigCore::core_verify`. Mirrors the `SecretKeySet`/`combine` split
+used by the `threshold_crypto` crate.
+*/
+
+use crate::signature::BLSSigCore;
+use ff::{Field, PrimeField};
+use pairing::CurveProjective;
+use rand::RngCore;
+use std::collections::HashSet;
+
+/// Alias for the scalar field of the curve a `BLSSigCore` impl signs over.
+type Scalar<C> = <C as CurveProjective>::Scalar;
+
+/// Map a 1-based party index to its evaluation point in `Fr`.
+fn index_to_scalar<C: BLSSigCore>(index: u64) -> Scalar<C> {
+    Scalar::<C>::from_str(&index.to_string()).expect("index fits in the scalar field")
+}
+
+/// The `\lambda_i = \prod_{j \ne i} j / (j - i)` Lagrange coefficient for
+/// interpolating at `x = 0`, given the set of indices contributing shares.
+fn lagrange_coefficient<C: BLSSigCore>(i: u64, indices: &[u64]) -> Scalar<C> {
+    let xi = index_to_scalar::<C>(i);
+    let mut num = Scalar::<C>::one();
+    let mut den = Scalar::<C>::one();
+    for &j in indices {
+        if j == i {
+            continue;
+        }
+        let xj = index_to_scalar::<C>(j);
+        num.mul_assign(&xj);
+        let mut diff = xj;
+        diff.sub_assign(&xi);
+        den.mul_assign(&diff);
+    }
+    num.mul_assign(&den.inverse().expect("distinct indices give a nonzero denominator"));
+    num
+}
+
+/// A random degree-`t` sharing polynomial `f(X) = a_0 + a_1 X + ... + a_t
+/// X^t` over `Fr`. `a_0` is the master secret key; `g^{a_0}` is the
+/// master public key.
+pub struct SecretKeySet<C: BLSSigCore> {
+    coeffs: Vec<Scalar<C>>,
+}
+
+impl<C: BLSSigCore> SecretKeySet<C> {
+    /// Sample a random degree-`threshold` polynomial; `threshold + 1`
+    /// shares are then required to reconstruct the secret.
+    pub fn random<R: RngCore>(threshold: usize, rng: &mut R) -> Self {
+        let coeffs = (0..=threshold).map(|_| Scalar::<C>::random(rng)).collect();
+        SecretKeySet { coeffs }
+    }
+
+    /// The reconstruction threshold `t`: `t + 1` shares are required.
+    pub fn threshold(&self) -> usize {
+        self.coeffs.len() - 1
+    }
+
+    /// Evaluate the sharing polynomial at `x = index` via Horner's method.
+    fn eval(&self, index: u64) -> Scalar<C> {
+        let x = index_to_scalar::<C>(index);
+        let mut acc = Scalar::<C>::zero();
+        for coeff in self.coeffs.iter().rev() {
+            acc.mul_assign(&x);
+            acc.add_assign(coeff);
+        }
+        acc
+    }
+
+    /// Derive party `index`'s secret key share (`index` ranges `1..=n`).
+    pub fn secret_key_share(&self, index: u64) -> SecretKeyShare<C> {
+        SecretKeyShare {
+            index,
+            x_prime: self.eval(index),
+        }
+    }
+
+    /// The master public key `g^{a_0}`.
+    pub fn public_key(&self) -> C::PKType {
+        let mut pk = C::PKType::one();
+        pk.mul_assign(self.coeffs[0]);
+        pk
+    }
+
+    /// Derive party `index`'s public key share `g^{f(index)}`.
+    pub fn public_key_share(&self, index: u64) -> PublicKeyShare<C> {
+        let mut pk = C::PKType::one();
+        pk.mul_assign(self.eval(index));
+        PublicKeyShare { index, pk }
+    }
+
+    /// The Feldman commitment `[g^{a_0}, ..., g^{a_t}]` to this
+    /// polynomial's coefficients, used by the DKG to let recipients
+    /// verify shares without learning the polynomial itself.
+    pub(crate) fn commitment(&self) -> Vec<C::PKType> {
+        self.coeffs
+            .iter()
+            .map(|a| {
+                let mut g = C::PKType::one();
+                g.mul_assign(*a);
+                g
+            })
+            .collect()
+    }
+}
+
+/// One party's secret key share `f(index)`.
+#[derive(Clone, Copy)]
+pub struct SecretKeyShare<C: BLSSigCore> {
+    pub(crate) index: u64,
+    pub(crate) x_prime: Scalar<C>,
+}
+
+impl<C: BLSSigCore> SecretKeyShare<C> {
+    /// The party index this share belongs to.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Produce a partial signature `sig_i = f(i) \cdot H(msg)`.
+    pub fn sign<B: AsRef<[u8]>>(&self, msg: B, ciphersuite: u8) -> C {
+        C::core_sign(self.x_prime, msg, ciphersuite)
+    }
+
+    /// This share's public key `g^{f(index)}`.
+    pub fn public_key_share(&self) -> PublicKeyShare<C> {
+        let mut pk = C::PKType::one();
+        pk.mul_assign(self.x_prime);
+        PublicKeyShare {
+            index: self.index,
+            pk,
+        }
+    }
+
+    /// Sum several shares a single party holds for the same index (one
+    /// per DKG dealer) into that party's final secret key share.
+    pub fn sum(index: u64, shares: &[SecretKeyShare<C>]) -> SecretKeyShare<C> {
+        assert!(
+            shares.iter().all(|s| s.index == index),
+            "all shares being summed must belong to the same party"
+        );
+        let mut acc = Scalar::<C>::zero();
+        for s in shares {
+            acc.add_assign(&s.x_prime);
+        }
+        SecretKeyShare {
+            index,
+            x_prime: acc,
+        }
+    }
+}
+
+/// One party's public key share `g^{f(index)}`.
+#[derive(Clone, Copy)]
+pub struct PublicKeyShare<C: BLSSigCore> {
+    pub(crate) index: u64,
+    pub(crate) pk: C::PKType,
+}
+
+impl<C: BLSSigCore> PublicKeyShare<C> {
+    /// The party index this share belongs to.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// The raw public key point backing this share.
+    pub fn public_key(&self) -> C::PKType {
+        self.pk
+    }
+
+    /// Verify a partial signature against this share's public key.
+    pub fn verify<B: AsRef<[u8]>>(&self, sig: C, msg: B, ciphersuite: u8) -> bool {
+        C::core_verify(self.pk, sig, msg, ciphersuite)
+    }
+
+    /// Sum several public key shares a single party holds for the same
+    /// index (one per DKG dealer) into that party's final public key
+    /// share.
+    pub fn sum(index: u64, shares: &[PublicKeyShare<C>]) -> PublicKeyShare<C> {
+        assert!(
+            shares.iter().all(|s| s.index == index),
+            "all shares being summed must belong to the same party"
+        );
+        let mut acc = C::PKType::zero();
+        for s in shares {
+            acc.add_assign(&s.pk);
+        }
+        PublicKeyShare { index, pk: acc }
+    }
+}
+
+/// Reconstruct the group signature from `t + 1` (or more) partial
+/// signatures by Lagrange interpolation at `X = 0`. Returns `None` if
+/// fewer than `threshold + 1` distinct indices are supplied. The result
+/// verifies against the master public key with the unchanged
+/// `BLSSigCore::core_verify`.
+///
+/// Callers should verify each partial against its `PublicKeyShare`
+/// before combining, so a single bad contributor can be identified
+/// instead of silently corrupting the group signature.
+pub fn combine<C: BLSSigCore>(partials: &[(u64, C)], threshold: usize) -> Option<C> {
+    let mut seen = HashSet::<u64>::with_capacity(partials.len());
+    let unique: Vec<(u64, C)> = partials
+        .iter()
+        .filter(|(i, _)| seen.insert(*i))
+        .copied()
+        .collect();
+    if unique.len() < threshold + 1 {
+        return None;
+    }
+
+    let indices: Vec<u64> = unique.iter().map(|(i, _)| *i).collect();
+    let mut acc = C::zero();
+    for (i, sig) in &unique {
+        let lambda = lagrange_coefficient::<C>(*i, &indices);
+        let mut term = *sig;
+        term.mul_assign(lambda);
+        acc.add_assign(&term);
+    }
+    Some(acc)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use pairing::bls12_381::G1;
+    use rand::thread_rng;
+
+    const CIPHERSUITE: u8 = 1;
+    const MSG: &[u8] = b"threshold test message";
+
+    #[test]
+    fn combine_with_threshold_plus_one_shares_verifies_under_master_pk() {
+        let mut rng = thread_rng();
+        let threshold = 2;
+        let poly = SecretKeySet::<G1>::random(threshold, &mut rng);
+        let master_pk = poly.public_key();
+
+        let partials: Vec<(u64, G1)> = (1..=(threshold as u64 + 1))
+            .map(|i| {
+                let share = poly.secret_key_share(i);
+                let sig = share.sign(MSG, CIPHERSUITE);
+                assert!(share.public_key_share().verify(sig, MSG, CIPHERSUITE));
+                (i, sig)
+            })
+            .collect();
+
+        let sig = combine::<G1>(&partials, threshold).expect("t+1 shares should combine");
+        assert!(G1::core_verify(master_pk, sig, MSG, CIPHERSUITE));
+    }
+
+    #[test]
+    fn combine_with_only_threshold_shares_fails() {
+        let mut rng = thread_rng();
+        let threshold = 2;
+        let poly = SecretKeySet::<G1>::random(threshold, &mut rng);
+
+        let partials: Vec<(u64, G1)> = (1..=(threshold as u64))
+            .map(|i| (i, poly.secret_key_share(i).sign(MSG, CIPHERSUITE)))
+            .collect();
+
+        assert!(combine::<G1>(&partials, threshold).is_none());
+    }
+
+    #[test]
+    fn combine_ignores_duplicate_indices() {
+        let mut rng = thread_rng();
+        let threshold = 1;
+        let poly = SecretKeySet::<G1>::random(threshold, &mut rng);
+        let master_pk = poly.public_key();
+
+        let share1 = poly.secret_key_share(1);
+        let share2 = poly.secret_key_share(2);
+        let partials = vec![
+            (1, share1.sign(MSG, CIPHERSUITE)),
+            (1, share1.sign(MSG, CIPHERSUITE)),
+            (2, share2.sign(MSG, CIPHERSUITE)),
+        ];
+
+        let sig = combine::<G1>(&partials, threshold).expect("two distinct indices suffice");
+        assert!(G1::core_verify(master_pk, sig, MSG, CIPHERSUITE));
+    }
+}
@@ -0,0 +1,231 @@
+/*!
+Compact wire codecs for keys, signatures, and aggregates
+
+Gives public keys, signatures, and their aggregates first-class
+round-trippable encodings, instead of reaching for the inline `SerDes`
+cursor dance that `pop_prove`/`pk_bytes` use internally. `to_bytes`/
+`from_bytes` round-trip through the canonical compressed form (48 bytes
+for a G1 point, 96 bytes for a G2 point), rejecting malformed or
+non-canonical encodings. With the `serde` feature enabled, the same
+types also implement `serde::Serialize`/`Deserialize`, so they can be
+embedded in JSON/bincode payloads - e.g. by downstream consensus code
+persisting or transmitting a common-coin share.
+*/
+
+use pairing::bls12_381::{G1, G2};
+use pairing::serdes::SerDes;
+use pairing::CurveProjective;
+use std::io;
+use std::io::Cursor;
+
+/// A G1 point in 48-byte compressed form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompressedG1(pub [u8; 48]);
+
+/// A G2 point in 96-byte compressed form.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompressedG2(pub [u8; 96]);
+
+impl CompressedG1 {
+    /// Serialize a G1 point to its 48-byte compressed form.
+    pub fn to_bytes(p: &G1) -> Self {
+        let mut buf = [0u8; 48];
+        let mut cur = Cursor::new(&mut buf[..]);
+        p.serialize(&mut cur, true)
+            .expect("compressed G1 serialization into a 48-byte buffer cannot fail");
+        CompressedG1(buf)
+    }
+
+    /// Parse a compressed G1 point, rejecting malformed or non-canonical
+    /// encodings.
+    pub fn from_bytes(bytes: [u8; 48]) -> io::Result<G1> {
+        let mut cur = Cursor::new(&bytes[..]);
+        G1::deserialize(&mut cur, true)
+    }
+}
+
+impl CompressedG2 {
+    /// Serialize a G2 point to its 96-byte compressed form.
+    pub fn to_bytes(p: &G2) -> Self {
+        let mut buf = [0u8; 96];
+        let mut cur = Cursor::new(&mut buf[..]);
+        p.serialize(&mut cur, true)
+            .expect("compressed G2 serialization into a 96-byte buffer cannot fail");
+        CompressedG2(buf)
+    }
+
+    /// Parse a compressed G2 point, rejecting malformed or non-canonical
+    /// encodings.
+    pub fn from_bytes(bytes: [u8; 96]) -> io::Result<G2> {
+        let mut cur = Cursor::new(&bytes[..]);
+        G2::deserialize(&mut cur, true)
+    }
+}
+
+/// An aggregated BLS signature (the sum of individual G1 signatures),
+/// persisted and transmitted as a single 48-byte compressed point.
+///
+/// Modeled on the `AggregateSignature` type from `ockam_signature_bls`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AggregateSignature(CompressedG1);
+
+impl AggregateSignature {
+    /// Wrap an aggregated G1 signature for storage/transmission.
+    pub fn from_point(sig: &G1) -> Self {
+        AggregateSignature(CompressedG1::to_bytes(sig))
+    }
+
+    /// Recover the aggregated G1 signature, rejecting malformed or
+    /// non-canonical encodings.
+    pub fn to_point(&self) -> io::Result<G1> {
+        CompressedG1::from_bytes((self.0).0)
+    }
+}
+
+/// An aggregated BLS public key (the sum of individual G2 public keys),
+/// persisted and transmitted as a single 96-byte compressed point.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AggregatePublicKey(CompressedG2);
+
+impl AggregatePublicKey {
+    /// Wrap an aggregated G2 public key for storage/transmission.
+    pub fn from_point(pk: &G2) -> Self {
+        AggregatePublicKey(CompressedG2::to_bytes(pk))
+    }
+
+    /// Recover the aggregated G2 public key, rejecting malformed or
+    /// non-canonical encodings.
+    pub fn to_point(&self) -> io::Result<G2> {
+        CompressedG2::from_bytes((self.0).0)
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{AggregatePublicKey, AggregateSignature, CompressedG1, CompressedG2};
+    use serde::de::Error as DeError;
+    use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+    fn serialize_bytes<S: Serializer>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(bytes)
+    }
+
+    fn deserialize_array<'de, D: Deserializer<'de>, const N: usize>(
+        deserializer: D,
+    ) -> Result<[u8; N], D::Error> {
+        let bytes = <Vec<u8>>::deserialize(deserializer)?;
+        if bytes.len() != N {
+            return Err(DeError::invalid_length(bytes.len(), &N.to_string().as_str()));
+        }
+        let mut buf = [0u8; N];
+        buf.copy_from_slice(&bytes);
+        Ok(buf)
+    }
+
+    macro_rules! impl_serde_for_compressed_point {
+        ($point:ty, $size:expr) => {
+            impl Serialize for $point {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serialize_bytes(&self.0, serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $point {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    let buf = deserialize_array::<D, $size>(deserializer)?;
+                    // reject non-canonical/malformed encodings eagerly
+                    Self::from_bytes(buf).map_err(DeError::custom)?;
+                    Ok(Self(buf))
+                }
+            }
+        };
+    }
+
+    impl_serde_for_compressed_point!(CompressedG1, 48);
+    impl_serde_for_compressed_point!(CompressedG2, 96);
+
+    macro_rules! impl_serde_for_aggregate {
+        ($aggregate:ty, $inner:ty) => {
+            impl Serialize for $aggregate {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    self.0.serialize(serializer)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $aggregate {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    <$inner>::deserialize(deserializer).map(Self)
+                }
+            }
+        };
+    }
+
+    impl_serde_for_aggregate!(AggregateSignature, CompressedG1);
+    impl_serde_for_aggregate!(AggregatePublicKey, CompressedG2);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn compressed_g1_round_trips() {
+        let mut p = G1::one();
+        p.double();
+        let bytes = CompressedG1::to_bytes(&p);
+        let decoded = CompressedG1::from_bytes(bytes.0).expect("canonical encoding decodes");
+        assert!(decoded == p);
+    }
+
+    #[test]
+    fn compressed_g2_round_trips() {
+        let mut p = G2::one();
+        p.double();
+        let bytes = CompressedG2::to_bytes(&p);
+        let decoded = CompressedG2::from_bytes(bytes.0).expect("canonical encoding decodes");
+        assert!(decoded == p);
+    }
+
+    #[test]
+    fn aggregate_signature_and_public_key_round_trip() {
+        let sig_point = G1::one();
+        let pk_point = G2::one();
+
+        let sig = AggregateSignature::from_point(&sig_point);
+        assert!(sig.to_point().expect("canonical encoding decodes") == sig_point);
+
+        let pk = AggregatePublicKey::from_point(&pk_point);
+        assert!(pk.to_point().expect("canonical encoding decodes") == pk_point);
+    }
+
+    #[test]
+    fn non_canonical_encoding_is_rejected() {
+        // All-0xff is not a valid compressed-point encoding for any curve
+        // point: it sets the point-at-infinity flag while leaving the
+        // remaining bits nonzero, and those bits exceed the field modulus.
+        assert!(CompressedG1::from_bytes([0xffu8; 48]).is_err());
+        assert!(CompressedG2::from_bytes([0xffu8; 96]).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod serde_tests {
+    use super::*;
+
+    #[test]
+    fn compressed_g1_serde_round_trips() {
+        let p = G1::one();
+        let compressed = CompressedG1::to_bytes(&p);
+        let json = serde_json::to_vec(&compressed).expect("serialize");
+        let decoded: CompressedG1 = serde_json::from_slice(&json).expect("deserialize");
+        assert!(decoded == compressed);
+    }
+
+    #[test]
+    fn compressed_g1_serde_rejects_overlength_encoding() {
+        let mut bytes = CompressedG1::to_bytes(&G1::one()).0.to_vec();
+        bytes.push(0);
+        let json = serde_json::to_vec(&bytes).expect("serialize");
+        assert!(serde_json::from_slice::<CompressedG1>(&json).is_err());
+    }
+}